@@ -12,7 +12,9 @@
 use std::collections::HashMap;
 
 extern crate rand;
+use rand::distributions::Distribution;
 use rand::Rng;
+use rand::SeedableRng;
 
 pub trait ProbabilityTable<T, R> {
     /// Add an option to the random table with the assigned weight value.
@@ -111,7 +113,30 @@ pub trait ProbabilityTable<T, R> {
     /// ```
     fn random(&mut self) -> Result<T, String>;
 
-    //fn set_generator(rng: R);
+    /// Replace the random generator backing this table. Combined with a
+    /// [`SeedableRng`](rand::SeedableRng) generator, this lets you drop in a
+    /// freshly-seeded `R` to make a table's output reproducible.
+    fn set_generator(&mut self, rng: R);
+
+    /// Run `n` trials in a single call and collect the results.
+    ///
+    /// Each pick goes through [`random`](ProbabilityTable::random), so the
+    /// stateful tables thread their "memory" across the batch just as they would
+    /// over `n` separate calls: [`FairlyRandomTable`] redistributes weights
+    /// between picks, and a [`DeckTable`] draws without replacement. For
+    /// [`RandomTable`] the picks are simply `n` independent weighted draws.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered -- if a draw fails partway through
+    /// (for example an exhausted [`DeckTable`]), no partial batch is returned.
+    fn random_n(&mut self, n: usize) -> Result<Vec<T>, String> {
+        let mut results = Vec::with_capacity(n);
+        for _ in 0..n {
+            results.push(self.random()?);
+        }
+        Ok(results)
+    }
 }
 
 /// `RandomTable` represents a table of options and their relative weights. The
@@ -127,18 +152,124 @@ pub trait ProbabilityTable<T, R> {
 /// and can often lead to outcomes which (in games, at least) feel unfair.
 pub struct RandomTable<T, R> {
     pub(crate) table: HashMap<T, f64>,
+    /// Keys in insertion order. `HashMap` iteration order is randomized per
+    /// instance, so we keep an explicit order to make sampling deterministic
+    /// for a given sequence of `push` calls and RNG seed. `from_map` takes its
+    /// order from the iteration order of the map it is given.
+    pub(crate) order: Vec<T>,
     pub(crate) total: f64,
     pub(crate) rng: R,
+    pub(crate) alias: Option<AliasSampler<T>>,
+}
+
+/// Precomputed lookup tables for Vose's alias method.
+///
+/// Building the tables is `O(n)` in the number of options, but once built a
+/// single draw is `O(1)` -- pick a bucket uniformly, then flip a biased coin to
+/// decide between that bucket's item and its alias. This is the same trick
+/// `rand`'s weighted alias-method distribution uses, and it replaces the old
+/// linear scan that [`RandomTable`] used to run on every call.
+///
+/// The sampler is rebuilt lazily: [`RandomTable`] clears it whenever the weights
+/// change (via `push`/`from_map`) and recomputes it on the next `random()`.
+pub(crate) struct AliasSampler<T> {
+    items: Vec<T>,
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl<T: Clone> AliasSampler<T> {
+    /// Build the alias tables from a weight map, skipping any option whose
+    /// weight is not strictly positive. Returns `None` when nothing is left to
+    /// sample from.
+    pub(crate) fn build(order: &[T], table: &HashMap<T, f64>) -> Option<AliasSampler<T>>
+    where
+        T: std::cmp::Eq + std::hash::Hash,
+    {
+        let mut items = Vec::with_capacity(order.len());
+        let mut weights = Vec::with_capacity(order.len());
+        for ident in order {
+            if let Some(weight) = table.get(ident) {
+                if *weight > 0f64 {
+                    items.push(ident.clone());
+                    weights.push(*weight);
+                }
+            }
+        }
+
+        let n = items.len();
+        if n == 0 {
+            return None;
+        }
+
+        // Normalize the weights to sum to 1, then scale by `n` so each entry is
+        // measured against an average bucket of size 1.
+        let sum: f64 = weights.iter().sum();
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w / sum * n as f64).collect();
+
+        let mut prob = vec![0f64; n];
+        let mut alias = vec![0usize; n];
+
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        for (i, value) in scaled.iter().enumerate() {
+            if *value < 1f64 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while !small.is_empty() && !large.is_empty() {
+            let s = small.pop().unwrap();
+            let l = large.pop().unwrap();
+
+            prob[s] = scaled[s];
+            alias[s] = l;
+
+            scaled[l] -= 1f64 - scaled[s];
+            if scaled[l] < 1f64 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+
+        // Anything still in a worklist is a full bucket. `large` drains here in
+        // the exact case; `small` only has leftovers because of floating-point
+        // rounding, but either way the remaining buckets are certain.
+        for i in large {
+            prob[i] = 1f64;
+        }
+        for i in small {
+            prob[i] = 1f64;
+        }
+
+        Some(AliasSampler { items, prob, alias })
+    }
+
+    /// Draw a single item in `O(1)` using the supplied generator.
+    pub(crate) fn sample<R: Rng>(&self, rng: &mut R) -> T {
+        let i = rng.gen_range(0..self.items.len());
+        let f = rng.gen::<f64>();
+        if f < self.prob[i] {
+            self.items[i].clone()
+        } else {
+            self.items[self.alias[i]].clone()
+        }
+    }
 }
 
 // RandomTable
-impl<T: std::cmp::Eq + std::hash::Hash, R: Rng> RandomTable<T, R> {
+impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> RandomTable<T, R> {
     /// Create a new instance of `RandomTable` with no options.
     pub fn new(rng: R) -> RandomTable<T, R> {
         RandomTable {
             table: HashMap::new(),
+            order: Vec::new(),
             total: 0f64,
             rng,
+            alias: None,
         }
     }
 
@@ -182,18 +313,26 @@ impl<T: std::cmp::Eq + std::hash::Hash, R: Rng> RandomTable<T, R> {
             total += entry.1
         }
 
+        let order = in_table.keys().cloned().collect();
+
         RandomTable {
             table: in_table,
-            total: total,
+            order,
+            total,
             rng,
+            alias: None,
         }
     }
 }
 
 impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> ProbabilityTable<T, R> for RandomTable<T, R> {
     fn push(&mut self, ident: T, weight: f64) -> &mut dyn ProbabilityTable<T, R> {
-        self.table.insert(ident, weight);
+        if self.table.insert(ident.clone(), weight).is_none() {
+            self.order.push(ident);
+        }
         self.total += weight;
+        // The weights changed, so the cached alias tables are stale.
+        self.alias = None;
         self
     }
 
@@ -206,16 +345,94 @@ impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> ProbabilityTable<T, R> f
     }
 
     fn random(&mut self) -> Result<T, String> {
-        let r = self.rng.gen::<f64>() * self.total;
+        if self.alias.is_none() {
+            self.alias = AliasSampler::build(&self.order, &self.table);
+        }
+
+        match &self.alias {
+            Some(sampler) => Ok(sampler.sample(&mut self.rng)),
+            None => Err("Generated random outside of possible range".to_owned()),
+        }
+    }
+
+    fn set_generator(&mut self, rng: R) {
+        self.rng = rng;
+    }
+}
+
+// Seedable construction for reproducible sampling.
+impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng + SeedableRng> RandomTable<T, R> {
+    /// Create an empty `RandomTable` whose generator is built from a fixed
+    /// seed. Two tables built from the same seed and the same sequence of
+    /// `push` calls produce byte-for-byte identical draw sequences, because the
+    /// insertion-ordered `order` vector fixes the sampling order independently
+    /// of `HashMap`'s per-instance iteration randomization.
+    pub fn from_seed(seed: R::Seed) -> RandomTable<T, R> {
+        RandomTable::new(R::from_seed(seed))
+    }
+
+    /// Reset the table's generator from a fixed seed, rewinding the sequence it
+    /// will produce from the next draw onward.
+    pub fn reseed(&mut self, seed: R::Seed) {
+        self.rng = R::from_seed(seed);
+    }
+}
+
+// Iterator adaptor over the rand `Distribution` impl.
+impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> RandomTable<T, R> {
+    /// Return an endless iterator of independent weighted draws from this table,
+    /// driven by the supplied generator.
+    ///
+    /// This is the [`Distribution`]-based counterpart to
+    /// [`random_n`](ProbabilityTable::random_n): it borrows the table immutably
+    /// and leaves the table's own stored generator untouched, so it composes
+    /// with the `rand` ecosystem exactly like `rng.sample_iter(&table)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use droprate::{RandomTable, ProbabilityTable};
+    /// use rand::prelude::*;
+    ///
+    /// let mut table = RandomTable::<&'static str, ThreadRng>::new(ThreadRng::default());
+    /// table.push("A", 1f64);
+    ///
+    /// let draws: Vec<&'static str> = table.sample_iter(ThreadRng::default()).take(10).collect();
+    /// assert_eq!(10, draws.len());
+    /// ```
+    pub fn sample_iter<RR: Rng>(
+        &self,
+        rng: RR,
+    ) -> rand::distributions::DistIter<&RandomTable<T, R>, RR, T> {
+        Distribution::sample_iter(self, rng)
+    }
+}
+
+/// Implementing [`Distribution`] lets a `RandomTable` plug into the wider `rand`
+/// ecosystem: callers can write `rng.sample(&table)`,
+/// `rng.sample_iter(&table).take(100)`, or hand the table to anything that
+/// expects a `Distribution`. Unlike [`RandomTable::random`], sampling here
+/// borrows the table immutably and draws from whatever generator the caller
+/// supplies, so the table's own stored `rng` is left untouched.
+///
+/// The weight-walking logic mirrors the table's original scan. Because
+/// `Distribution::sample` is infallible, sampling an empty table (or one whose
+/// weights are all non-positive) panics rather than returning an error; use
+/// [`RandomTable::random`] when you need the fallible form.
+impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> Distribution<T> for RandomTable<T, R> {
+    fn sample<RR: Rng + ?Sized>(&self, rng: &mut RR) -> T {
+        let r = rng.gen::<f64>() * self.total;
         let mut comp = r;
-        for pair in &self.table {
-            if *pair.1 > comp {
-                return Ok(pair.0.clone());
+        for ident in &self.order {
+            if let Some(weight) = self.table.get(ident) {
+                if *weight > 0f64 && *weight > comp {
+                    return ident.clone();
+                }
+                comp -= *weight;
             }
-            comp -= pair.1;
         }
 
-        Err("Generated random outside of possible range".to_owned())
+        panic!("Generated random outside of possible range");
     }
 }
 
@@ -302,21 +519,27 @@ impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> FairlyRandomTable<T, R>
         FairlyRandomTable {
             base: RandomTable::from_map(in_table.clone(), rng),
             table: in_table,
-            total: total,
+            total,
         }
     }
 
     /// Run a trial from this as though it were a [`RandomTable`]. The table's
     /// results memory will not be affected, and as such future results from
     /// calling `random()` will not account for this trial.
-    pub fn pure_random(&self) -> Result<T, String> {
-        let r = rand::random::<f64>() * self.total;
+    ///
+    /// This draws from the table's own generator `R`, so it advances the same
+    /// reproducible stream as [`FairlyRandomTable::random`] rather than the
+    /// global thread-local generator.
+    pub fn pure_random(&mut self) -> Result<T, String> {
+        let r = self.base.rng.gen::<f64>() * self.total;
         let mut comp = r;
-        for pair in &self.base.table {
-            if *pair.1 > comp {
-                return Ok(pair.0.clone());
+        for ident in &self.base.order {
+            if let Some(weight) = self.base.table.get(ident) {
+                if *weight > comp {
+                    return Ok(ident.clone());
+                }
+                comp -= *weight;
             }
-            comp -= pair.1;
         }
 
         Err("Generated random outside of possible range".to_owned())
@@ -360,10 +583,10 @@ impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> ProbabilityTable<T, R> f
     }
 
     fn random(&mut self) -> Result<T, String> {
-        let r = rand::random::<f64>() * self.total;
+        let r = self.base.rng.gen::<f64>() * self.total;
         let mut comp = r;
 
-        let keys = self.table.keys().cloned();
+        let keys = self.base.order.clone();
         let mut match_pair: Option<(T, f64)> = None;
 
         for key in keys {
@@ -377,15 +600,338 @@ impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> ProbabilityTable<T, R> f
             }
         }
 
-        match match_pair {
-            Some(pair) => {
-                self.table.entry(pair.0.clone()).and_modify(|e| *e = 0f64);
-                self.redistribute_weights(pair.1);
-                return Ok(pair.0.clone());
-            }
-            None => {}
+        if let Some(pair) = match_pair {
+            self.table.entry(pair.0.clone()).and_modify(|e| *e = 0f64);
+            self.redistribute_weights(pair.1);
+            return Ok(pair.0.clone());
         }
 
         Err("Generated random outside of possible range".to_owned())
     }
+
+    fn set_generator(&mut self, rng: R) {
+        self.base.set_generator(rng);
+    }
+}
+
+// Seedable construction for reproducible sampling.
+impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng + SeedableRng> FairlyRandomTable<T, R> {
+    /// Create an empty `FairlyRandomTable` whose generator is built from a fixed
+    /// seed, for reproducible "fair" sequences in tests and replays. Like
+    /// [`RandomTable::from_seed`], reproducibility holds for tables built from
+    /// the same seed and the same sequence of `push` calls, since draws walk the
+    /// insertion-ordered keys rather than `HashMap` iteration order.
+    pub fn from_seed(seed: R::Seed) -> FairlyRandomTable<T, R> {
+        FairlyRandomTable::new(R::from_seed(seed))
+    }
+
+    /// Reset the table's generator from a fixed seed. Note this only rewinds the
+    /// random stream; any results memory accumulated from previous draws is left
+    /// intact.
+    pub fn reseed(&mut self, seed: R::Seed) {
+        self.base.reseed(seed);
+    }
+}
+
+/// Controls what a [`DeckTable`] does once every card has been drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeckMode {
+    /// Return an error from `random()` when the deck is empty. The caller is
+    /// expected to `reshuffle()` explicitly before drawing again.
+    Error,
+    /// Transparently `reshuffle()` the original weights and keep drawing, so the
+    /// deck behaves like an endlessly recycled shoe.
+    Reshuffle,
+}
+
+/// `DeckTable` models the deck-of-cards scenario from the crate-level docs:
+/// each `random()` draws proportional to the weights that remain, then removes
+/// that item from the deck. Repeated draws therefore produce a
+/// weighted shuffle -- sampling *without* replacement -- until the deck is
+/// exhausted.
+///
+/// What happens at exhaustion is governed by [`DeckMode`]: either `random()`
+/// errors, or the deck reshuffles itself and keeps going. [`DeckTable::reshuffle`]
+/// restores the original weights on demand, and [`DeckTable::draw_all`] returns
+/// the entire weighted permutation in one call -- the weighted analogue of
+/// shuffling a slice.
+pub struct DeckTable<T, R> {
+    pub(crate) base: HashMap<T, f64>,
+    pub(crate) base_order: Vec<T>,
+    pub(crate) table: HashMap<T, f64>,
+    /// Keys still in the deck, in insertion order. Drawing a card removes it
+    /// from both `table` and `order`, so [`count`](DeckTable::count) shrinks as
+    /// the deck depletes and draws never walk dead entries.
+    pub(crate) order: Vec<T>,
+    pub(crate) total: f64,
+    pub(crate) mode: DeckMode,
+    pub(crate) rng: R,
+}
+
+//
+// DeckTable
+//
+impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> DeckTable<T, R> {
+    /// Create a new, empty `DeckTable`. The deck defaults to [`DeckMode::Error`];
+    /// use [`DeckTable::set_mode`] to opt into automatic reshuffling.
+    pub fn new(rng: R) -> DeckTable<T, R> {
+        DeckTable {
+            base: HashMap::new(),
+            base_order: Vec::new(),
+            table: HashMap::new(),
+            order: Vec::new(),
+            total: 0f64,
+            mode: DeckMode::Error,
+            rng,
+        }
+    }
+
+    /// Create a new `DeckTable` from a [`HashMap`] of weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use droprate::{DeckTable, ProbabilityTable};
+    /// use rand::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let map: HashMap<&'static str, f64> =
+    ///     [("A", 1f64),
+    ///     ("B", 1f64),
+    ///     ("C", 3f64)]
+    ///     .iter().cloned().collect();
+    ///
+    /// let mut table = DeckTable::<&'static str, ThreadRng>::from_map(map, ThreadRng::default());
+    ///
+    /// assert_eq!(3, table.count());
+    /// ```
+    pub fn from_map(in_table: HashMap<T, f64>, rng: R) -> DeckTable<T, R> {
+        let mut total = 0f64;
+        for entry in &in_table {
+            total += entry.1
+        }
+
+        let order: Vec<T> = in_table.keys().cloned().collect();
+
+        DeckTable {
+            base: in_table.clone(),
+            base_order: order.clone(),
+            table: in_table,
+            order,
+            total,
+            mode: DeckMode::Error,
+            rng,
+        }
+    }
+
+    /// Set the behavior used when the deck runs out of cards.
+    pub fn set_mode(&mut self, mode: DeckMode) -> &mut DeckTable<T, R> {
+        self.mode = mode;
+        self
+    }
+
+    /// Restore every option to its original weight, as though the deck had been
+    /// gathered up and shuffled fresh.
+    pub fn reshuffle(&mut self) {
+        self.table = self.base.clone();
+        self.order = self.base_order.clone();
+        self.total = self.base.values().sum();
+    }
+
+    /// Draw a single card proportional to the remaining weights and remove it
+    /// from the deck, returning [`None`] once the deck is empty.
+    fn draw_one(&mut self) -> Option<T> {
+        let r = self.rng.gen::<f64>() * self.total;
+        let mut comp = r;
+
+        let mut match_index: Option<usize> = None;
+
+        for (i, key) in self.order.iter().enumerate() {
+            if let Some(val) = self.table.get(key) {
+                if *val > comp {
+                    match_index = Some(i);
+                    break;
+                }
+                comp -= *val;
+            }
+        }
+
+        match match_index {
+            Some(i) => {
+                let key = self.order.remove(i);
+                if let Some(weight) = self.table.remove(&key) {
+                    self.total -= weight;
+                }
+                Some(key)
+            }
+            None => None,
+        }
+    }
+
+    /// Draw the entire remaining deck at once, returning the full weighted
+    /// permutation in draw order. This ignores [`DeckMode`] -- it always stops
+    /// when the current deck is exhausted rather than reshuffling.
+    pub fn draw_all(&mut self) -> Vec<T> {
+        let mut results = Vec::with_capacity(self.table.len());
+        while let Some(item) = self.draw_one() {
+            results.push(item);
+        }
+        results
+    }
+}
+
+impl<T: std::cmp::Eq + std::hash::Hash + Clone, R: Rng> ProbabilityTable<T, R> for DeckTable<T, R> {
+    fn push(&mut self, ident: T, weight: f64) -> &mut dyn ProbabilityTable<T, R> {
+        if self.base.insert(ident.clone(), weight).is_none() {
+            self.base_order.push(ident.clone());
+        }
+        if self.table.insert(ident.clone(), weight).is_none() {
+            self.order.push(ident);
+        }
+        self.total += weight;
+        self
+    }
+
+    /// The number of cards *still in the deck*. Each draw removes a card, so
+    /// this shrinks as the deck depletes and returns to the full count after a
+    /// [`reshuffle`](DeckTable::reshuffle).
+    fn count(&self) -> usize {
+        self.table.len()
+    }
+
+    fn keys(&self) -> Vec<T> {
+        self.order.clone()
+    }
+
+    fn random(&mut self) -> Result<T, String> {
+        if self.order.is_empty() || self.total <= 0f64 {
+            match self.mode {
+                DeckMode::Error => {
+                    return Err("Deck is exhausted".to_owned());
+                }
+                DeckMode::Reshuffle => self.reshuffle(),
+            }
+        }
+
+        match self.draw_one() {
+            Some(item) => Ok(item),
+            None => Err("Generated random outside of possible range".to_owned()),
+        }
+    }
+
+    fn set_generator(&mut self, rng: R) {
+        self.rng = rng;
+    }
+}
+
+/// A summary of everything a [`RunStats`] recorder observed over a run.
+///
+/// The fields answer the two questions the `droprate` test suite used to ask
+/// with `println!`: how bunched up are the results (the longest run of
+/// identical picks) and how widely are they spaced (the biggest gap between two
+/// picks of the same item). Comparing these between a [`RandomTable`] and a
+/// [`FairlyRandomTable`] puts a number on the "feels unfair" phenomenon the
+/// `FairlyRandomTable` docs describe.
+#[derive(Clone, Debug)]
+pub struct RunStatsSummary<T> {
+    /// Total number of trials recorded.
+    pub trials: u64,
+    /// How many times each item came up.
+    pub counts: HashMap<T, u64>,
+    /// The longest streak of the same item appearing on consecutive trials.
+    pub longest_run: u64,
+    /// Per item, the largest gap between two successive occurrences of that
+    /// item, measured as the difference in 1-based trial index (occurrences on
+    /// trials 2 and 4 give a gap of 2). Items seen fewer than twice have no
+    /// entry.
+    pub max_gap: HashMap<T, u64>,
+}
+
+/// `RunStats` records the sequence a table produces and reports on its shape.
+///
+/// It is deliberately independent of any particular table: feed it results with
+/// [`record`](RunStats::record), or let it drive a table for you with
+/// [`record_random`](RunStats::record_random), then call
+/// [`summary`](RunStats::summary). This is the home for the streak/gap analysis
+/// that used to live as standing TODOs in the integration tests.
+pub struct RunStats<T> {
+    counts: HashMap<T, u64>,
+    max_gap: HashMap<T, u64>,
+    last_seen: HashMap<T, u64>,
+    last_result: Option<T>,
+    current_run: u64,
+    longest_run: u64,
+    trials: u64,
+}
+
+impl<T: std::cmp::Eq + std::hash::Hash + Clone> RunStats<T> {
+    /// Create an empty recorder.
+    pub fn new() -> RunStats<T> {
+        RunStats {
+            counts: HashMap::new(),
+            max_gap: HashMap::new(),
+            last_seen: HashMap::new(),
+            last_result: None,
+            current_run: 0,
+            longest_run: 0,
+            trials: 0,
+        }
+    }
+
+    /// Record a single trial result, updating the running counts, streak, and
+    /// gap tallies.
+    pub fn record(&mut self, item: T) {
+        self.trials += 1;
+
+        *self.counts.entry(item.clone()).or_insert(0) += 1;
+
+        // Longest streak of the same item in a row.
+        if self.last_result.as_ref() == Some(&item) {
+            self.current_run += 1;
+        } else {
+            self.current_run = 1;
+        }
+        if self.current_run > self.longest_run {
+            self.longest_run = self.current_run;
+        }
+
+        // Largest gap between two successive occurrences of this item.
+        if let Some(previous) = self.last_seen.get(&item) {
+            let gap = self.trials - previous;
+            let entry = self.max_gap.entry(item.clone()).or_insert(0);
+            if gap > *entry {
+                *entry = gap;
+            }
+        }
+        self.last_seen.insert(item.clone(), self.trials);
+
+        self.last_result = Some(item);
+    }
+
+    /// Draw one result from a table and record it, returning the drawn value.
+    /// This is the "wrap a table" convenience: loop over it to accumulate a run.
+    pub fn record_random<R>(
+        &mut self,
+        table: &mut dyn ProbabilityTable<T, R>,
+    ) -> Result<T, String> {
+        let result = table.random()?;
+        self.record(result.clone());
+        Ok(result)
+    }
+
+    /// Produce a snapshot summary of everything recorded so far.
+    pub fn summary(&self) -> RunStatsSummary<T> {
+        RunStatsSummary {
+            trials: self.trials,
+            counts: self.counts.clone(),
+            longest_run: self.longest_run,
+            max_gap: self.max_gap.clone(),
+        }
+    }
+}
+
+impl<T: std::cmp::Eq + std::hash::Hash + Clone> Default for RunStats<T> {
+    fn default() -> RunStats<T> {
+        RunStats::new()
+    }
 }
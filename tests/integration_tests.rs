@@ -1,8 +1,12 @@
-use droprate::{FairlyRandomTable, ProbabilityTable, RandomTable};
+use droprate::{DeckMode, DeckTable, FairlyRandomTable, ProbabilityTable, RandomTable, RunStats};
+
+use rand::rngs::{SmallRng, StdRng, ThreadRng};
+use rand::Rng;
+use rand::SeedableRng;
 
 use std::collections::HashMap;
 
-fn gen_string_table() -> RandomTable<String> {
+fn gen_string_table() -> RandomTable<String, ThreadRng> {
     let outcomes: HashMap<String, f64> = [
         (String::from("first"), 1f64),
         (String::from("second"), 1f64),
@@ -11,10 +15,10 @@ fn gen_string_table() -> RandomTable<String> {
     .iter()
     .cloned()
     .collect();
-    RandomTable::from_map(outcomes)
+    RandomTable::from_map(outcomes, ThreadRng::default())
 }
 
-fn gen_fairly_random_string_table() -> FairlyRandomTable<String> {
+fn gen_fairly_random_string_table() -> FairlyRandomTable<String, ThreadRng> {
     let outcomes: HashMap<String, f64> = [
         (String::from("first"), 1f64),
         (String::from("second"), 1f64),
@@ -23,12 +27,12 @@ fn gen_fairly_random_string_table() -> FairlyRandomTable<String> {
     .iter()
     .cloned()
     .collect();
-    FairlyRandomTable::from_map(outcomes)
+    FairlyRandomTable::from_map(outcomes, ThreadRng::default())
 }
 
 #[test]
 fn empty_table() {
-    let table = RandomTable::<String>::new();
+    let table = RandomTable::<String, ThreadRng>::new(ThreadRng::default());
     assert_eq!(table.count(), 0);
 }
 
@@ -43,7 +47,7 @@ fn populated_table() {
     .cloned()
     .collect();
 
-    let mut table = RandomTable::from_map(outcomes);
+    let mut table = RandomTable::from_map(outcomes, ThreadRng::default());
     assert_eq!(table.count(), 3);
 
     let fail_string = String::from("fail");
@@ -64,15 +68,16 @@ fn seems_always_valid() {
     }
 }
 
-// TODO: report longest distance between two items of the same
-// TODO: report longest streak of same items in a row
+// Longest-gap and longest-streak reporting now lives in the library as
+// `RunStats`/`RunStatsSummary`; feed it results (or let it drive a table with
+// `record_random`) and read `summary()` instead of eyeballing `println!`.
 
-fn random_odds(table: &mut ProbabilityTable<String>, num_cycles: u32) {
+fn random_odds<R: Rng>(table: &mut dyn ProbabilityTable<String, R>, num_cycles: u32) {
     let keys = table.keys();
-    let mut stats = HashMap::<&String, u64>::new();
+    let mut stats = HashMap::<String, u64>::new();
 
     for k in &keys {
-        stats.insert(k, 0);
+        stats.insert(k.clone(), 0);
     }
 
     for _ in 0..num_cycles {
@@ -97,3 +102,189 @@ fn report_reactive_probability() {
     let num_cycles = 10000u32;
     random_odds(&mut gen_fairly_random_string_table(), num_cycles);
 }
+
+//
+// Deterministic, seedable sampling (chunk0-4)
+//
+
+#[test]
+fn reseed_reproduces_sequence() {
+    let mut table = RandomTable::<&'static str, StdRng>::from_seed([42u8; 32]);
+    table.push("a", 1f64).push("b", 2f64).push("c", 3f64);
+
+    let first = table.random_n(64).unwrap();
+
+    table.reseed([42u8; 32]);
+    let second = table.random_n(64).unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn same_seed_same_pushes_match_across_tables() {
+    let mut one = RandomTable::<&'static str, StdRng>::from_seed([9u8; 32]);
+    one.push("a", 1f64).push("b", 2f64).push("c", 3f64);
+
+    let mut two = RandomTable::<&'static str, StdRng>::from_seed([9u8; 32]);
+    two.push("a", 1f64).push("b", 2f64).push("c", 3f64);
+
+    assert_eq!(one.random_n(64).unwrap(), two.random_n(64).unwrap());
+}
+
+#[test]
+fn fairly_random_is_reproducible_when_seeded() {
+    let mut one = FairlyRandomTable::<&'static str, StdRng>::from_seed([3u8; 32]);
+    one.push("a", 1f64).push("b", 2f64).push("c", 3f64);
+
+    let mut two = FairlyRandomTable::<&'static str, StdRng>::from_seed([3u8; 32]);
+    two.push("a", 1f64).push("b", 2f64).push("c", 3f64);
+
+    assert_eq!(one.random_n(64).unwrap(), two.random_n(64).unwrap());
+}
+
+//
+// DeckTable (chunk0-3)
+//
+
+fn gen_deck() -> DeckTable<&'static str, StdRng> {
+    let mut deck = DeckTable::<&'static str, StdRng>::new(StdRng::from_seed([5u8; 32]));
+    deck.push("a", 1f64).push("b", 2f64).push("c", 3f64);
+    deck
+}
+
+#[test]
+fn draw_all_is_a_permutation_without_repeats() {
+    let mut deck = gen_deck();
+    let drawn = deck.draw_all();
+
+    assert_eq!(3, drawn.len());
+    assert!(drawn.contains(&"a"));
+    assert!(drawn.contains(&"b"));
+    assert!(drawn.contains(&"c"));
+
+    // Drawing removes cards, so the deck is now empty.
+    assert_eq!(0, deck.count());
+}
+
+#[test]
+fn count_shrinks_as_the_deck_depletes() {
+    let mut deck = gen_deck();
+    assert_eq!(3, deck.count());
+    deck.random().unwrap();
+    assert_eq!(2, deck.count());
+    deck.random().unwrap();
+    deck.random().unwrap();
+    assert_eq!(0, deck.count());
+}
+
+#[test]
+fn error_mode_errors_after_exhaustion() {
+    let mut deck = gen_deck();
+    for _ in 0..3 {
+        assert!(deck.random().is_ok());
+    }
+    assert!(deck.random().is_err());
+}
+
+#[test]
+fn reshuffle_restores_the_deck() {
+    let mut deck = gen_deck();
+    deck.draw_all();
+    assert_eq!(0, deck.count());
+    deck.reshuffle();
+    assert_eq!(3, deck.count());
+}
+
+#[test]
+fn reshuffle_mode_keeps_drawing() {
+    let mut deck = gen_deck();
+    deck.set_mode(DeckMode::Reshuffle);
+    // Well past a single deck's worth of cards; it should never error.
+    for _ in 0..20 {
+        assert!(deck.random().is_ok());
+    }
+}
+
+//
+// Alias sampler coverage (chunk0-1)
+//
+
+fn gen_seeded_table() -> RandomTable<&'static str, StdRng> {
+    let mut table = RandomTable::<&'static str, StdRng>::from_seed([7u8; 32]);
+    table.push("a", 1f64).push("b", 2f64).push("c", 3f64);
+    table
+}
+
+#[test]
+fn alias_sampler_covers_every_weighted_item() {
+    let mut table = gen_seeded_table();
+    let mut counts: HashMap<&'static str, u64> = HashMap::new();
+
+    for _ in 0..10000 {
+        let result = table.random().unwrap();
+        *counts.entry(result).or_insert(0) += 1;
+    }
+
+    // Every positively-weighted item is reachable, and the heavier item wins
+    // more often than the lighter one.
+    assert!(counts.get("a").copied().unwrap_or(0) > 0);
+    assert!(counts.get("b").copied().unwrap_or(0) > 0);
+    assert!(counts.get("c").copied().unwrap_or(0) > 0);
+    assert!(counts["c"] > counts["a"]);
+}
+
+//
+// Distribution impl (chunk0-2)
+//
+
+#[test]
+fn distribution_sample_iter_yields_n_items() {
+    let table = gen_seeded_table();
+    let draws: Vec<&'static str> =
+        table.sample_iter(SmallRng::seed_from_u64(1)).take(50).collect();
+    assert_eq!(50, draws.len());
+    assert!(draws.iter().all(|d| *d == "a" || *d == "b" || *d == "c"));
+}
+
+#[test]
+#[should_panic]
+fn distribution_sample_panics_on_empty_table() {
+    let table = RandomTable::<&'static str, StdRng>::from_seed([0u8; 32]);
+    let mut rng = SmallRng::seed_from_u64(2);
+    // No options have been pushed, so the weight-walk falls off the end.
+    let _ = rng.sample(&table);
+}
+
+//
+// RunStats (chunk0-6)
+//
+
+#[test]
+fn run_stats_tracks_counts_runs_and_gaps() {
+    let mut stats = RunStats::<&'static str>::new();
+    for item in ["a", "a", "b", "a"] {
+        stats.record(item);
+    }
+
+    let summary = stats.summary();
+    assert_eq!(4, summary.trials);
+    assert_eq!(3, summary.counts["a"]);
+    assert_eq!(1, summary.counts["b"]);
+    // "a","a" is the longest consecutive streak.
+    assert_eq!(2, summary.longest_run);
+    // `a` occurs at trials 1, 2, and 4. `max_gap` is the largest difference
+    // between two *successive* occurrences: 2 - 1 = 1, then 4 - 2 = 2, so 2.
+    assert_eq!(2, summary.max_gap["a"]);
+    // `b` appears once, so it never establishes a gap.
+    assert!(!summary.max_gap.contains_key("b"));
+}
+
+#[test]
+fn run_stats_can_drive_a_table() {
+    let mut table = gen_string_table();
+    let mut stats = RunStats::<String>::new();
+    for _ in 0..1000 {
+        stats.record_random(&mut table).unwrap();
+    }
+    assert_eq!(1000, stats.summary().trials);
+}